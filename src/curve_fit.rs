@@ -7,6 +7,41 @@ pub fn chi2(y: &Array1<f64>, ymodel: &Array1<f64>, sy: &Array1<f64>) -> f64 {
     ((y - ymodel) / sy).map(|x| x.powi(2)).sum()
 }
 
+/// Strategy used to solve the linear system at each LM step
+#[derive(Clone, Copy, PartialEq)]
+pub enum SolveMethod {
+    /// Form J^T W J + lambda diag(J^T W J) and solve with `matrix_solve`
+    NormalEquations,
+    /// Solve the augmented, weighted system `[J̃ ; sqrt(lambda) diag(d)] delta = [r̃ ; 0]`
+    /// by Householder QR with column pivoting, without ever forming J^T W J
+    PivotedQR,
+}
+
+/// Overall optimization strategy driving `minimize()`
+#[derive(Clone, Copy, PartialEq)]
+pub enum Method {
+    /// Levenberg-Marquardt, steered by the multiplicative `lambda` heuristic
+    LevenbergMarquardt,
+    /// Powell's dogleg, a trust-region method that steers the step length
+    /// directly via `trust_region_radius`
+    Dogleg,
+    /// Nonlinear conjugate gradient, a gradient-only method that forms the
+    /// Jacobian's Gram matrix only once, after the iterations, for the
+    /// covariance matrix; well suited to fits with many parameters
+    ConjugateGradient,
+}
+
+/// Basis from which the parameter covariance matrix is formed at the end of the fit
+pub enum CovarianceBasis {
+    /// J^T W J, inverted via LU decomposition
+    NormalEquations(Array2<f64>),
+    /// Upper-triangular R factor (in pivoted column order) of the
+    /// *undamped* weighted Jacobian `J̃` alone (not the damped, augmented
+    /// system solved for `delta`), together with the pivot order:
+    /// `(R^T R)^-1`, unpermuted, equals the parameter covariance matrix
+    PivotedQR(Array2<f64>, Vec<usize>),
+}
+
 /// Contains all relevant information after one minimization step
 pub struct MinimizationStep {
     parameters: Array1<f64>,
@@ -17,7 +52,7 @@ pub struct MinimizationStep {
     metric: f64,
     metric_gradient: f64,
     metric_parameters: f64,
-    JT_W_J: Array2<f64>,
+    covariance_basis: CovarianceBasis,
 }
 
 /// Container to perform a curve fit for model, given y and & sy
@@ -31,6 +66,8 @@ pub struct Minimizer<'a> {
     pub y: &'a Array1<f64>,
     pub sy: &'a Array1<f64>,
     pub vary_parameter: &'a Array1<bool>,
+    pub lower_bounds: Array1<f64>,
+    pub upper_bounds: Array1<f64>,
     pub weighting_matrix: Array1<f64>,
     pub minimizer_parameters: Array1<f64>,
     pub minimizer_ymodel: Array1<f64>,
@@ -53,6 +90,14 @@ pub struct Minimizer<'a> {
     pub epsilon4: f64,
     pub lambda_UP_fac: f64,
     pub lambda_DOWN_fac: f64,
+    pub solve_method: SolveMethod,
+    pub method: Method,
+    pub trust_region_radius: f64,
+    pub trust_region_radius_max: f64,
+    pub use_geodesic_acceleration: bool,
+    pub alpha: f64,
+    pub geodesic_h: f64,
+    pub cg_chi2_rel_tol: f64,
 }
 
 impl<'a> Minimizer<'a> {
@@ -99,6 +144,8 @@ impl<'a> Minimizer<'a> {
             y: &y,
             sy: &sy,
             vary_parameter: &vary_parameter,
+            lower_bounds: Array1::from_elem(num_params, f64::NEG_INFINITY),
+            upper_bounds: Array1::from_elem(num_params, f64::INFINITY),
             weighting_matrix: weighting_matrix,
             minimizer_parameters: initial_parameters,
             minimizer_ymodel: minimizer_ymodel,
@@ -121,15 +168,52 @@ impl<'a> Minimizer<'a> {
             epsilon4: 1e-1,
             lambda_UP_fac: 11.0,
             lambda_DOWN_fac: 9.0,
+            solve_method: SolveMethod::NormalEquations,
+            method: Method::LevenbergMarquardt,
+            trust_region_radius: 1.0,
+            trust_region_radius_max: 1e3,
+            use_geodesic_acceleration: false,
+            alpha: 0.75,
+            geodesic_h: 0.1,
+            cg_chi2_rel_tol: 1e-8,
         }
     }
 
+    /// Restricts the parameters to a feasible box `[lower, upper]`
+    ///
+    /// Unconstrained parameters keep their default bound of `±inf`. Bounds are
+    /// enforced in `lm()` by projecting the updated parameters back into the
+    /// box after each step.
+    pub fn set_parameter_bounds(&mut self, lower_bounds: Array1<f64>, upper_bounds: Array1<f64>) {
+        assert_eq!(lower_bounds.len(), self.num_params);
+        assert_eq!(upper_bounds.len(), self.num_params);
+        self.lower_bounds = lower_bounds;
+        self.upper_bounds = upper_bounds;
+    }
+
     /// Performs a Levenberg Marquardt step
     ///
     /// determine change to parameters by solving the equation
     /// [J^T W J + lambda diag(J^T W J)] delta = J^T W (y - f)
-    /// for delta
+    /// for delta, using either `SolveMethod::NormalEquations` or
+    /// `SolveMethod::PivotedQR` (see `solve_method`), then project the
+    /// candidate parameters into the feasible box given by
+    /// `lower_bounds`/`upper_bounds`. `use_geodesic_acceleration` only takes
+    /// effect under `SolveMethod::NormalEquations`.
     pub fn lm(&mut self) -> MinimizationStep {
+        match self.solve_method {
+            SolveMethod::NormalEquations => self.lm_normal_equations(),
+            SolveMethod::PivotedQR => self.lm_pivoted_qr(),
+        }
+    }
+
+    /// Solves the LM step via the normal equations
+    /// [J^T W J + lambda diag(J^T W J)] delta = J^T W (y - f)
+    ///
+    /// When `use_geodesic_acceleration` is set, the first-order velocity is
+    /// corrected with the second-order geodesic-acceleration term (see
+    /// `apply_geodesic_acceleration`)
+    fn lm_normal_equations(&mut self) -> MinimizationStep {
         // J^T is cloned to be multiplied by weighting_matrix later
         let mut jt = self.jacobian.clone().reversed_axes();
 
@@ -146,15 +230,179 @@ impl<'a> Minimizer<'a> {
         // first J^T W J
         let JT_W_J = jt.dot(&self.jacobian);
 
-        let lambdaDiagJT_W_J = self.lambda * &JT_W_J.diag();
+        let lambda_diag = self.lambda * &JT_W_J.diag();
         let mut A = JT_W_J.clone();
         for i in 0..self.num_varying_params {
-            A[[i, i]] += lambdaDiagJT_W_J[i];
+            A[[i, i]] += lambda_diag[i];
         }
 
         // solve LM step for delta
-        let delta: Array1<f64> = matrix_solve(&A, &b);
+        let mut delta: Array1<f64> = matrix_solve(&A, &b);
+
+        if self.use_geodesic_acceleration {
+            delta = self.apply_geodesic_acceleration(&delta, &jt, &A);
+        }
+
+        let damping_term = (0..self.num_varying_params)
+            .map(|i| delta[i].powi(2) * lambda_diag[i])
+            .sum();
+
+        self.finish_step(delta, &b, damping_term, CovarianceBasis::NormalEquations(JT_W_J))
+    }
+
+    /// Corrects the first-order velocity `v1` with the geodesic-acceleration
+    /// term `v2`, a second-order LM correction that accounts for the curvature
+    /// of the model along `v1`.
+    ///
+    /// `v2` solves the same damped system as `v1`, `[J^T W J + lambda diag] v2
+    /// = -J^T W a_dir`, where `a_dir` is a finite-difference estimate of the
+    /// directional second derivative of the model along `v1`. The correction
+    /// costs one extra model evaluation (no extra Jacobian) and is only kept
+    /// when the ratio test `2‖v2‖/‖v1‖ ≤ alpha` passes; otherwise the step
+    /// falls back to `v1` alone.
+    fn apply_geodesic_acceleration(
+        &mut self,
+        v1: &Array1<f64>,
+        jt: &Array2<f64>,
+        a: &Array2<f64>,
+    ) -> Array1<f64> {
+        let h = self.geodesic_h;
+        let v1_full = self.expand(v1);
+        let mut perturbed_parameters = &self.minimizer_parameters + &(h * &v1_full);
+        for i in 0..self.num_params {
+            perturbed_parameters[i] = perturbed_parameters[i]
+                .max(self.lower_bounds[i])
+                .min(self.upper_bounds[i]);
+        }
+        let perturbed_model = self.model.for_parameters(&perturbed_parameters);
+        self.num_func_evaluation += 1;
+
+        let a_dir = (2.0 / h)
+            * ((&perturbed_model - &self.minimizer_ymodel) / h - self.jacobian.dot(v1));
+
+        let rhs_v2 = -jt.dot(&a_dir);
+        let v2: Array1<f64> = matrix_solve(a, &rhs_v2);
+
+        let v1_norm = v1.dot(v1).sqrt();
+        let v2_norm = v2.dot(&v2).sqrt();
+        if v1_norm > 0.0 && 2.0 * v2_norm / v1_norm <= self.alpha {
+            v1 + 0.5 * &v2
+        } else {
+            v1.clone()
+        }
+    }
+
+    /// Solves the LM step without ever forming J^T W J: builds the weighted
+    /// Jacobian `J̃ = diag(sqrt(W)) J` and weighted residual `r̃ = sqrt(W)(y-f)`,
+    /// stacks the damping rows `sqrt(lambda) diag(d)` below `J̃`, and solves the
+    /// resulting `(num_data + num_varying_params) x num_varying_params`
+    /// least-squares system by Householder QR with column pivoting
+    fn lm_pivoted_qr(&mut self) -> MinimizationStep {
+        let residual = self.y - &self.minimizer_ymodel;
+
+        // J^T W (y - f), still needed for the gradient convergence metric
+        let mut jt = self.jacobian.clone().reversed_axes();
+        for i in 0..self.num_data {
+            let mut col = jt.column_mut(i);
+            col *= self.weighting_matrix[i];
+        }
+        let b = jt.dot(&residual);
+
+        // J̃ = diag(sqrt(W)) J  and  r̃ = sqrt(W) (y - f)
+        let sqrt_w = self.weighting_matrix.map(|w| w.sqrt());
+        let mut j_tilde = self.jacobian.clone();
+        for i in 0..self.num_data {
+            let mut row = j_tilde.row_mut(i);
+            row *= sqrt_w[i];
+        }
+        let r_tilde = &residual * &sqrt_w;
+
+        // diag(J^T W J), computed directly without forming the full Gram matrix
+        let lambda_diag = self.lambda * &self.weighted_jacobian_diag();
+
+        // stack the damping rows: [J̃ ; sqrt(lambda) diag(d)] delta = [r̃ ; 0]
+        let augmented_rows = self.num_data + self.num_varying_params;
+        let mut augmented = Array2::zeros((augmented_rows, self.num_varying_params));
+        augmented
+            .slice_mut(s![0..self.num_data, ..])
+            .assign(&j_tilde);
+        for i in 0..self.num_varying_params {
+            augmented[[self.num_data + i, i]] = lambda_diag[i].sqrt();
+        }
+        let mut rhs = Array1::zeros(augmented_rows);
+        rhs.slice_mut(s![0..self.num_data]).assign(&r_tilde);
+
+        let (delta, _, _) = pivoted_qr_solve(augmented, rhs);
+
+        // R (and its pivot order) for the covariance matrix must come from a QR of
+        // the *undamped* J̃ alone: R of the damped, augmented system factors
+        // J^T W J + lambda diag(J^T W J), not J^T W J, and would bias the
+        // reported errors by the regularization
+        let (_, r_factor, perm) =
+            pivoted_qr_solve(j_tilde, Array1::zeros(self.num_data));
+
+        let damping_term = (0..self.num_varying_params)
+            .map(|i| delta[i].powi(2) * lambda_diag[i])
+            .sum();
+
+        self.finish_step(
+            delta,
+            &b,
+            damping_term,
+            CovarianceBasis::PivotedQR(r_factor, perm),
+        )
+    }
+
+    /// Diagonal of J^T W J, without forming the full Gram matrix
+    fn weighted_jacobian_diag(&self) -> Array1<f64> {
+        let mut diag = Array1::zeros(self.num_varying_params);
+        for k in 0..self.num_varying_params {
+            let col = self.jacobian.column(k);
+            let mut sum = 0.0;
+            for i in 0..self.num_data {
+                sum += self.weighting_matrix[i] * col[i].powi(2);
+            }
+            diag[k] = sum;
+        }
+        diag
+    }
+
+    /// Infinity-norm of `gradient`, restricted to the varying parameters, excluding
+    /// any free variable that sits exactly on a bound with `gradient` pushing it
+    /// further outside the box -- such a variable cannot move further towards the
+    /// optimum and would otherwise keep the metric from ever reaching zero.
+    /// `descent_sign` is `1.0` for a vector like `b = J^T W (y-f)`, which points
+    /// towards decreasing chi2, and `-1.0` for `g = -2 J^T W (y-f)`, which points
+    /// towards increasing chi2, so the same test serves the LM, Dogleg and
+    /// conjugate-gradient convergence checks
+    fn bound_aware_gradient_metric(&self, gradient: &Array1<f64>, descent_sign: f64) -> f64 {
+        let mut idx_vary_param = 0;
+        let mut metric: f64 = 0.0;
+        for i in 0..self.num_params {
+            if self.vary_parameter[i] {
+                let at_lower = self.minimizer_parameters[i] <= self.lower_bounds[i];
+                let at_upper = self.minimizer_parameters[i] >= self.upper_bounds[i];
+                let descent = descent_sign * gradient[idx_vary_param];
+                let on_bound = (at_lower && descent < 0.0) || (at_upper && descent > 0.0);
+                if !on_bound {
+                    metric = metric.max(gradient[idx_vary_param].abs());
+                }
+                idx_vary_param += 1;
+            }
+        }
+        metric
+    }
 
+    /// Shared bookkeeping once `delta` has been solved for: builds the metrics
+    /// used for convergence, projects the candidate parameters into the
+    /// feasible box, and evaluates the model at the result
+    fn finish_step(
+        &self,
+        delta: Array1<f64>,
+        b: &Array1<f64>,
+        damping_term: f64,
+        covariance_basis: CovarianceBasis,
+    ) -> MinimizationStep {
         // create delta with length of total number of parameters
         let mut delta_all: Array1<f64> = Array1::zeros(self.num_params);
         let mut idx_vary_param = 0;
@@ -166,20 +414,12 @@ impl<'a> Minimizer<'a> {
         }
 
         // calculate metrics to determine convergence
-        let mut metric = delta.dot(&b);
-
-        for i in 0..self.num_varying_params {
-            metric += delta[i].powi(2) * lambdaDiagJT_W_J[i];
-        }
+        let metric = delta.dot(b) + damping_term;
 
         // take maximum of the absolute value in the respective arrays as metric for the
-        // convergence of either the gradient or the parameters
-        let metric_gradient = b
-            .map(|x| x.abs())
-            .to_vec()
-            .iter()
-            .cloned()
-            .fold(0. / 0., f64::max);
+        // convergence of either the gradient or the parameters; a free variable sitting
+        // exactly on a bound is excluded unless its gradient pushes back into the interior
+        let metric_gradient = self.bound_aware_gradient_metric(b, 1.0);
 
         let metric_parameters = (&delta_all / &self.minimizer_parameters)
             .map(|x| x.abs())
@@ -188,7 +428,13 @@ impl<'a> Minimizer<'a> {
             .cloned()
             .fold(0. / 0., f64::max);
 
-        let updated_parameters = &self.minimizer_parameters + &delta_all;
+        // project the candidate point back into the feasible box
+        let mut updated_parameters = &self.minimizer_parameters + &delta_all;
+        for i in 0..self.num_params {
+            updated_parameters[i] = updated_parameters[i]
+                .max(self.lower_bounds[i])
+                .min(self.upper_bounds[i]);
+        }
 
         let updated_model = self.model.for_parameters(&updated_parameters);
         let updated_chi2 = chi2(&self.y, &updated_model, &self.sy);
@@ -203,16 +449,27 @@ impl<'a> Minimizer<'a> {
             metric: metric,
             metric_gradient: metric_gradient,
             metric_parameters: metric_parameters,
-            JT_W_J: JT_W_J,
+            covariance_basis: covariance_basis,
+        }
+    }
+
+    /// Fit routine that performs steps until one convergence criterion is met,
+    /// using `Method::LevenbergMarquardt`, `Method::Dogleg` or
+    /// `Method::ConjugateGradient` (see `method`)
+    pub fn minimize(&mut self) {
+        match self.method {
+            Method::LevenbergMarquardt => self.minimize_lm(),
+            Method::Dogleg => self.minimize_dogleg(),
+            Method::ConjugateGradient => self.minimize_conjugate_gradient(),
         }
     }
 
     /// Fit routine that performs LM steps until one convergence criteria is met
     ///
     /// Follows the description from http://people.duke.edu/~hpgavin/ce281/lm.pdf
-    pub fn minimize(&mut self) {
+    fn minimize_lm(&mut self) {
         let mut iterations = 0;
-        let inverse_parameter_cov_matrix: Array2<f64>;
+        let covariance_basis: CovarianceBasis;
 
         loop {
             let update_step = self.lm();
@@ -262,26 +519,26 @@ impl<'a> Minimizer<'a> {
                 // gradient converged
                 if update_step.metric_gradient < self.epsilon1 {
                     self.convergence_message = "Gradient converged";
-                    inverse_parameter_cov_matrix = update_step.JT_W_J;
+                    covariance_basis = update_step.covariance_basis;
                     break;
                 };
 
                 // parameters converged
                 if update_step.metric_parameters < self.epsilon2 {
                     self.convergence_message = "Parameters converged";
-                    inverse_parameter_cov_matrix = update_step.JT_W_J;
+                    covariance_basis = update_step.covariance_basis;
                     break;
                 };
 
                 // chi2 converged
                 if update_step.redchi2 < self.epsilon3 {
                     self.convergence_message = "Chi2 converged";
-                    inverse_parameter_cov_matrix = update_step.JT_W_J;
+                    covariance_basis = update_step.covariance_basis;
                     break;
                 };
                 if iterations >= self.max_iterations {
                     self.convergence_message = "Reached max. number of iterations";
-                    inverse_parameter_cov_matrix = update_step.JT_W_J;
+                    covariance_basis = update_step.covariance_basis;
                     break;
                 }
             } else {
@@ -296,16 +553,477 @@ impl<'a> Minimizer<'a> {
             }
         }
 
-        // calculate parameter covariance matrix using the LU decomposition
-        let (L, U, P) = LU_decomp(&inverse_parameter_cov_matrix);
+        // calculate the parameter covariance matrix from whichever basis the
+        // accepted step produced
+        self.parameter_cov_matrix = match covariance_basis {
+            CovarianceBasis::NormalEquations(jtwj) => self.covariance_from_normal_equations(&jtwj),
+            CovarianceBasis::PivotedQR(r, perm) => {
+                // (R^T R)^-1 via two triangular solves per unit vector, reusing
+                // the QR R factor directly instead of forming J^T W J
+                let mut cov_pivoted =
+                    Array2::zeros((self.num_varying_params, self.num_varying_params));
+                for i in 0..self.num_varying_params {
+                    let mut unit_vector = Array1::zeros(self.num_varying_params);
+                    unit_vector[i] = 1.0;
+
+                    // R^T z = e_i by forward substitution (R^T is lower triangular)
+                    let mut z = Array1::zeros(self.num_varying_params);
+                    for row in 0..self.num_varying_params {
+                        let mut sum = unit_vector[row];
+                        for col in 0..row {
+                            sum -= r[[col, row]] * z[col];
+                        }
+                        z[row] = if r[[row, row]] != 0.0 {
+                            sum / r[[row, row]]
+                        } else {
+                            0.0
+                        };
+                    }
+
+                    // R x = z by back substitution
+                    let mut x = Array1::zeros(self.num_varying_params);
+                    for row in (0..self.num_varying_params).rev() {
+                        let mut sum = z[row];
+                        for col in (row + 1)..self.num_varying_params {
+                            sum -= r[[row, col]] * x[col];
+                        }
+                        x[row] = if r[[row, row]] != 0.0 {
+                            sum / r[[row, row]]
+                        } else {
+                            0.0
+                        };
+                    }
+
+                    let mut col_slice = cov_pivoted.slice_mut(s![.., i]);
+                    col_slice.assign(&x);
+                }
+
+                // undo the column pivoting: row/col i of cov_pivoted corresponds to perm[i]
+                let mut cov = Array2::zeros((self.num_varying_params, self.num_varying_params));
+                for i in 0..self.num_varying_params {
+                    for j in 0..self.num_varying_params {
+                        cov[[perm[i], perm[j]]] = cov_pivoted[[i, j]];
+                    }
+                }
+                cov
+            }
+        };
+        self.finalize_parameter_errors();
+    }
+
+    /// Fit routine that performs Powell dogleg trust-region steps until one
+    /// convergence criteria is met
+    ///
+    /// Each iteration computes the Gauss-Newton step `p_gn` by solving
+    /// `(J^T W J) p = J^T W (y-f)` and the Cauchy (steepest-descent) step
+    /// `p_cau`, then picks the dogleg point that stays within
+    /// `trust_region_radius`. The gain ratio between the actual and the
+    /// quadratic-model-predicted reduction in chi2 decides whether the step
+    /// is accepted and how the radius is adjusted for the next iteration.
+    fn minimize_dogleg(&mut self) {
+        let mut iterations = 0;
+        let jt_w_j_final: Array2<f64>;
+
+        loop {
+            // J^T W and J^T W (y - f), same gradient/Hessian-approximation building
+            // blocks as the LM normal-equations path, but without any damping
+            let mut jt = self.jacobian.clone().reversed_axes();
+            for i in 0..self.num_data {
+                let mut col = jt.column_mut(i);
+                col *= self.weighting_matrix[i];
+            }
+            let b = jt.dot(&(self.y - &self.minimizer_ymodel));
+            let jt_w_j = jt.dot(&self.jacobian);
+
+            // Gauss-Newton step
+            let p_gn: Array1<f64> = matrix_solve(&jt_w_j, &b);
+            let gn_norm = p_gn.dot(&p_gn).sqrt();
+
+            // Cauchy (steepest-descent) step: p_cau = -(g^T g / g^T B g) g, g = -b
+            let g = -&b;
+            let gt_b_g = g.dot(&jt_w_j.dot(&g));
+            let p_cau = if gt_b_g > 0.0 {
+                -(g.dot(&g) / gt_b_g) * &g
+            } else {
+                Array1::zeros(self.num_varying_params)
+            };
+            let cau_norm = p_cau.dot(&p_cau).sqrt();
+
+            // pick the dogleg point within the trust region
+            let p: Array1<f64> = if gn_norm <= self.trust_region_radius {
+                p_gn.clone()
+            } else if cau_norm >= self.trust_region_radius {
+                &p_cau * (self.trust_region_radius / cau_norm)
+            } else {
+                // solve ||p_cau + tau (p_gn - p_cau)||^2 = Delta^2 for tau in [0, 1]
+                let diff = &p_gn - &p_cau;
+                let a_coef = diff.dot(&diff);
+                let b_coef = 2.0 * p_cau.dot(&diff);
+                let c_coef = p_cau.dot(&p_cau) - self.trust_region_radius.powi(2);
+                let tau =
+                    (-b_coef + (b_coef.powi(2) - 4.0 * a_coef * c_coef).sqrt()) / (2.0 * a_coef);
+                &p_cau + &(tau * &diff)
+            };
+
+            // expand to the full parameter vector and project into the feasible box
+            let mut delta_all: Array1<f64> = Array1::zeros(self.num_params);
+            let mut idx_vary_param = 0;
+            for i in 0..self.num_params {
+                if self.vary_parameter[i] {
+                    delta_all[i] = p[idx_vary_param];
+                    idx_vary_param += 1;
+                }
+            }
+            let mut updated_parameters = &self.minimizer_parameters + &delta_all;
+            for i in 0..self.num_params {
+                updated_parameters[i] = updated_parameters[i]
+                    .max(self.lower_bounds[i])
+                    .min(self.upper_bounds[i]);
+            }
+
+            let updated_model = self.model.for_parameters(&updated_parameters);
+            let updated_chi2 = chi2(&self.y, &updated_model, &self.sy);
+
+            // gain ratio between actual and quadratic-model-predicted reduction
+            let predicted_reduction = 2.0 * b.dot(&p) - p.dot(&jt_w_j.dot(&p));
+            let rho = if predicted_reduction != 0.0 {
+                (self.chi2 - updated_chi2) / predicted_reduction
+            } else {
+                0.0
+            };
+
+            iterations += 1;
+
+            // shrink when the model predicted poorly, grow when it predicted well
+            // and the step was limited by the trust region boundary
+            let hit_boundary = gn_norm > self.trust_region_radius;
+            if rho < 0.25 {
+                self.trust_region_radius /= 2.0;
+            } else if rho > 0.75 && hit_boundary {
+                self.trust_region_radius =
+                    (2.0 * self.trust_region_radius).min(self.trust_region_radius_max);
+            }
+
+            if rho <= 0.0 {
+                // reject the step; try again from the same point with the shrunk radius
+                if iterations >= self.max_iterations {
+                    self.convergence_message = "Reached max. number of iterations";
+                    jt_w_j_final = jt_w_j;
+                    break;
+                }
+                continue;
+            }
+
+            // metrics are evaluated against the pre-step state, like the LM convergence tests;
+            // a free variable sitting exactly on a bound is excluded from the gradient metric
+            // unless its gradient pushes back into the interior of the box
+            let metric_gradient = self.bound_aware_gradient_metric(&b, 1.0);
+            let metric_parameters = (&delta_all / &self.minimizer_parameters)
+                .map(|x| x.abs())
+                .to_vec()
+                .iter()
+                .cloned()
+                .fold(0. / 0., f64::max);
+
+            // accept the step
+            self.minimizer_parameters = updated_parameters;
+            self.minimizer_ymodel = updated_model;
+            self.chi2 = updated_chi2;
+            self.redchi2 = updated_chi2 / (self.dof as f64);
+            self.jacobian = self.model.parameter_gradient(
+                &self.minimizer_parameters,
+                &self.vary_parameter,
+                &self.minimizer_ymodel,
+            );
+            self.num_func_evaluation += self.num_varying_params;
+
+            if metric_gradient < self.epsilon1 {
+                self.convergence_message = "Gradient converged";
+                jt_w_j_final = jt_w_j;
+                break;
+            }
+            if metric_parameters < self.epsilon2 {
+                self.convergence_message = "Parameters converged";
+                jt_w_j_final = jt_w_j;
+                break;
+            }
+            if self.redchi2 < self.epsilon3 {
+                self.convergence_message = "Chi2 converged";
+                jt_w_j_final = jt_w_j;
+                break;
+            }
+            if iterations >= self.max_iterations {
+                self.convergence_message = "Reached max. number of iterations";
+                jt_w_j_final = jt_w_j;
+                break;
+            }
+        }
+
+        self.parameter_cov_matrix = self.covariance_from_normal_equations(&jt_w_j_final);
+        self.finalize_parameter_errors();
+    }
+
+    /// Fit routine that performs nonlinear conjugate-gradient steps until one
+    /// convergence criteria is met
+    ///
+    /// Iterates on the gradient `g = -2 J^T W (y-f)` alone, without the Jacobian's
+    /// Gram matrix, which is formed only at the end for the covariance: each iteration searches along `s` with a bracketing-plus-zoom
+    /// line search that accepts a step satisfying the strong Wolfe conditions,
+    /// then updates `s` with the Polak-Ribiere formula.
+    fn minimize_conjugate_gradient(&mut self) {
+        let mut iterations = 0;
+        let mut g = self.current_gradient();
+        let mut s = -&g;
+        let mut failed_line_searches = 0;
+
+        loop {
+            // a free variable sitting exactly on a bound is excluded from the gradient
+            // metric unless its gradient pushes back into the interior of the box; `g`
+            // points towards increasing chi2, the opposite convention to `b`
+            let g_norm = self.bound_aware_gradient_metric(&g, -1.0);
+            if g_norm < self.epsilon1 {
+                self.convergence_message = "Gradient converged";
+                break;
+            }
+
+            let direction_full = self.expand(&s);
+
+            match self.line_search(&direction_full, &s, &g) {
+                Some((alpha, g_new)) => {
+                    failed_line_searches = 0;
+                    let chi2_old = self.chi2;
+                    let step_full = &direction_full * alpha;
+
+                    let mut updated_parameters = &self.minimizer_parameters + &step_full;
+                    for i in 0..self.num_params {
+                        updated_parameters[i] = updated_parameters[i]
+                            .max(self.lower_bounds[i])
+                            .min(self.upper_bounds[i]);
+                    }
+                    self.minimizer_parameters = updated_parameters;
+                    self.minimizer_ymodel = self.model.for_parameters(&self.minimizer_parameters);
+                    self.chi2 = chi2(&self.y, &self.minimizer_ymodel, &self.sy);
+                    self.redchi2 = self.chi2 / (self.dof as f64);
+
+                    iterations += 1;
+
+                    let metric_parameters = (&step_full / &self.minimizer_parameters)
+                        .map(|x| x.abs())
+                        .to_vec()
+                        .iter()
+                        .cloned()
+                        .fold(0. / 0., f64::max);
+                    if metric_parameters < self.epsilon2 {
+                        self.convergence_message = "Parameters converged";
+                        break;
+                    }
+                    // epsilon3 elsewhere gates an absolute `redchi2 < epsilon3` target, so a
+                    // separate, much tighter tolerance is used here for this *relative*
+                    // one-step decrease, which would otherwise declare convergence after a
+                    // single step that merely failed to cut chi2 by much under the default
+                    // epsilon3 of 1e-1
+                    if ((chi2_old - self.chi2) / chi2_old).abs() < self.cg_chi2_rel_tol {
+                        self.convergence_message = "Chi2 converged";
+                        break;
+                    }
+                    if iterations >= self.max_iterations {
+                        self.convergence_message = "Reached max. number of iterations";
+                        break;
+                    }
+
+                    // Polak-Ribiere update, clamped to zero (PR+)
+                    let beta = (g_new.dot(&(&g_new - &g)) / g.dot(&g)).max(0.0);
+                    let s_new = -&g_new + beta * &s;
+
+                    // restart along steepest descent whenever s fails to be a descent direction
+                    s = if s_new.dot(&g_new) < 0.0 { s_new } else { -&g_new };
+                    g = g_new;
+                }
+                None => {
+                    // line search failed; restart along steepest descent after repeated failures
+                    failed_line_searches += 1;
+                    if failed_line_searches >= 2 {
+                        s = -&g;
+                        failed_line_searches = 0;
+                    }
+                    iterations += 1;
+                    if iterations >= self.max_iterations {
+                        self.convergence_message = "Reached max. number of iterations";
+                        break;
+                    }
+                }
+            }
+        }
+
+        // approximate covariance from the Gauss-Newton Hessian at the final point,
+        // consistent with the other methods
+        self.jacobian = self.model.parameter_gradient(
+            &self.minimizer_parameters,
+            &self.vary_parameter,
+            &self.minimizer_ymodel,
+        );
+        let mut jt = self.jacobian.clone().reversed_axes();
+        for i in 0..self.num_data {
+            let mut col = jt.column_mut(i);
+            col *= self.weighting_matrix[i];
+        }
+        let jt_w_j = jt.dot(&self.jacobian);
+        self.parameter_cov_matrix = self.covariance_from_normal_equations(&jt_w_j);
+        self.finalize_parameter_errors();
+    }
+
+    /// Gradient `g = -2 J^T W (y-f)` at the current parameters, restricted to
+    /// the varying parameters; also refreshes `self.jacobian`
+    fn current_gradient(&mut self) -> Array1<f64> {
+        self.jacobian = self.model.parameter_gradient(
+            &self.minimizer_parameters,
+            &self.vary_parameter,
+            &self.minimizer_ymodel,
+        );
+        self.num_func_evaluation += self.num_varying_params;
+        let mut jt = self.jacobian.clone().reversed_axes();
+        for i in 0..self.num_data {
+            let mut col = jt.column_mut(i);
+            col *= self.weighting_matrix[i];
+        }
+        -2.0 * jt.dot(&(self.y - &self.minimizer_ymodel))
+    }
+
+    /// Expands a vector over the varying parameters into full parameter space,
+    /// leaving fixed parameters at zero
+    fn expand(&self, reduced: &Array1<f64>) -> Array1<f64> {
+        let mut full = Array1::zeros(self.num_params);
+        let mut idx_vary_param = 0;
+        for i in 0..self.num_params {
+            if self.vary_parameter[i] {
+                full[i] = reduced[idx_vary_param];
+                idx_vary_param += 1;
+            }
+        }
+        full
+    }
+
+    /// Evaluates `chi2` and the gradient (restricted to the varying parameters)
+    /// at `self.minimizer_parameters + alpha * direction_full`
+    fn evaluate_along(&mut self, direction_full: &Array1<f64>, alpha: f64) -> (f64, Array1<f64>) {
+        let trial = &self.minimizer_parameters + &(direction_full * alpha);
+        let ymodel = self.model.for_parameters(&trial);
+        let jac = self
+            .model
+            .parameter_gradient(&trial, &self.vary_parameter, &ymodel);
+        self.num_func_evaluation += self.num_varying_params;
+
+        let mut jt = jac.reversed_axes();
+        for i in 0..self.num_data {
+            let mut col = jt.column_mut(i);
+            col *= self.weighting_matrix[i];
+        }
+        let g = -2.0 * jt.dot(&(self.y - &ymodel));
+        let phi = chi2(&self.y, &ymodel, &self.sy);
+        (phi, g)
+    }
+
+    /// Bracketing line search along `direction_full` (`direction_reduced` is the
+    /// same direction restricted to the varying parameters, matching `g0`'s
+    /// space), accepting a step that satisfies the strong Wolfe conditions.
+    /// Returns `(alpha, gradient)` at the accepted point, or `None` if no
+    /// acceptable step was found within the iteration budget.
+    fn line_search(
+        &mut self,
+        direction_full: &Array1<f64>,
+        direction_reduced: &Array1<f64>,
+        g0: &Array1<f64>,
+    ) -> Option<(f64, Array1<f64>)> {
+        const C1: f64 = 1e-4;
+        const C2: f64 = 0.1;
+        const ALPHA_MAX: f64 = 1e4;
+
+        let phi0 = self.chi2;
+        let dphi0 = g0.dot(direction_reduced);
+        if dphi0 >= 0.0 {
+            return None;
+        }
+
+        let mut alpha_prev = 0.0;
+        let mut phi_prev = phi0;
+        let mut alpha = 1.0;
+
+        for _ in 0..20 {
+            let (phi, g) = self.evaluate_along(direction_full, alpha);
+            let dphi = g.dot(direction_reduced);
+
+            if phi > phi0 + C1 * alpha * dphi0 || (phi >= phi_prev && alpha_prev > 0.0) {
+                return self.zoom(direction_full, direction_reduced, phi0, dphi0, alpha_prev, alpha);
+            }
+            if dphi.abs() <= -C2 * dphi0 {
+                return Some((alpha, g));
+            }
+            if dphi >= 0.0 {
+                return self.zoom(direction_full, direction_reduced, phi0, dphi0, alpha, alpha_prev);
+            }
+
+            alpha_prev = alpha;
+            phi_prev = phi;
+            alpha = (2.0 * alpha).min(ALPHA_MAX);
+            if alpha >= ALPHA_MAX {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Shrinks the bracket `[lo, hi]` by interval bisection until a step
+    /// satisfying the strong Wolfe conditions is found
+    fn zoom(
+        &mut self,
+        direction_full: &Array1<f64>,
+        direction_reduced: &Array1<f64>,
+        phi0: f64,
+        dphi0: f64,
+        mut lo: f64,
+        mut hi: f64,
+    ) -> Option<(f64, Array1<f64>)> {
+        const C1: f64 = 1e-4;
+        const C2: f64 = 0.1;
+
+        let (mut phi_lo, _) = self.evaluate_along(direction_full, lo);
+
+        for _ in 0..20 {
+            let alpha = 0.5 * (lo + hi);
+            let (phi, g) = self.evaluate_along(direction_full, alpha);
+            let dphi = g.dot(direction_reduced);
+
+            if phi > phi0 + C1 * alpha * dphi0 || phi >= phi_lo {
+                hi = alpha;
+            } else {
+                if dphi.abs() <= -C2 * dphi0 {
+                    return Some((alpha, g));
+                }
+                if dphi * (hi - lo) >= 0.0 {
+                    hi = lo;
+                }
+                lo = alpha;
+                phi_lo = phi;
+            }
+        }
+        None
+    }
+
+    /// Inverts `J^T W J` via its LU decomposition to form the parameter covariance matrix
+    fn covariance_from_normal_equations(&self, jtwj: &Array2<f64>) -> Array2<f64> {
+        let (L, U, P) = LU_decomp(jtwj);
+        let mut cov = Array2::zeros((self.num_varying_params, self.num_varying_params));
         for i in 0..self.num_varying_params {
             let mut unit_vector = Array1::zeros(self.num_varying_params);
             unit_vector[i] = 1.0;
-            let mut col_slice = self.parameter_cov_matrix.slice_mut(s![.., i]);
+            let mut col_slice = cov.slice_mut(s![.., i]);
             col_slice.assign(&LU_matrix_solve(&L, &U, &P, &unit_vector));
         }
-        // parameter fit errors are the sqrt of the diagonal
+        cov
+    }
 
+    /// Parameter fit errors are the sqrt of the diagonal of the covariance matrix
+    fn finalize_parameter_errors(&mut self) {
         let mut idx_vary_param = 0;
         let mut all_errors: Array1<f64> = Array1::zeros(self.num_params);
         for i in 0..self.num_params {
@@ -358,3 +1076,100 @@ impl<'a> Minimizer<'a> {
         1.0 - res_sum_sq / tot_sum_sq
     }
 }
+
+/// Solves the least-squares problem `a x = b` (`a` is `m x n`, `m >= n`) by
+/// Householder QR with column pivoting, without ever forming `a^T a`.
+///
+/// Column pivoting brings the largest-norm remaining column to the front at
+/// each step, giving a rank-revealing factorization so rank-deficient columns
+/// degrade gracefully instead of producing garbage from a singular system.
+///
+/// Returns the solution `x` (in the original column order), the
+/// upper-triangular `R` factor in pivoted column order, and the pivot order
+/// (`perm[k]` is the original column index of the k-th pivoted column).
+fn pivoted_qr_solve(mut a: Array2<f64>, mut b: Array1<f64>) -> (Array1<f64>, Array2<f64>, Vec<usize>) {
+    let (m, n) = a.dim();
+    let mut perm: Vec<usize> = (0..n).collect();
+
+    for k in 0..n {
+        // bring the column with the largest remaining norm to the front
+        let mut pivot = k;
+        let mut pivot_norm = 0.0;
+        for j in k..n {
+            let norm: f64 = (k..m).map(|i| a[[i, j]].powi(2)).sum();
+            if norm > pivot_norm {
+                pivot_norm = norm;
+                pivot = j;
+            }
+        }
+        if pivot != k {
+            for i in 0..m {
+                a.swap((i, k), (i, pivot));
+            }
+            perm.swap(k, pivot);
+        }
+
+        // Householder reflector for column k, rows k..m
+        let col_norm = (k..m).map(|i| a[[i, k]].powi(2)).sum::<f64>().sqrt();
+        if col_norm == 0.0 {
+            // rank-deficient column: leave R[k, k] = 0 and move on
+            continue;
+        }
+        let alpha = if a[[k, k]] >= 0.0 { -col_norm } else { col_norm };
+        let mut v: Array1<f64> = Array1::zeros(m - k);
+        for i in k..m {
+            v[i - k] = a[[i, k]];
+        }
+        v[0] -= alpha;
+        let v_norm_sq = v.dot(&v);
+        if v_norm_sq == 0.0 {
+            continue;
+        }
+
+        // apply the reflector to the remaining columns (including k, so that R[k, k] = alpha)
+        for j in k..n {
+            let dot: f64 = (k..m).map(|i| v[i - k] * a[[i, j]]).sum();
+            let factor = 2.0 * dot / v_norm_sq;
+            for i in k..m {
+                a[[i, j]] -= factor * v[i - k];
+            }
+        }
+
+        // apply the same reflector to the right-hand side
+        let dot_b: f64 = (k..m).map(|i| v[i - k] * b[i]).sum();
+        let factor_b = 2.0 * dot_b / v_norm_sq;
+        for i in k..m {
+            b[i] -= factor_b * v[i - k];
+        }
+    }
+
+    // R is the upper-triangular n x n block of the reduced matrix
+    let mut r = Array2::zeros((n, n));
+    for i in 0..n {
+        for j in i..n {
+            r[[i, j]] = a[[i, j]];
+        }
+    }
+
+    // back-substitution for R x_pivoted = (Q^T b)[0..n]
+    let mut x_pivoted = Array1::zeros(n);
+    for i in (0..n).rev() {
+        let mut sum = b[i];
+        for j in (i + 1)..n {
+            sum -= r[[i, j]] * x_pivoted[j];
+        }
+        x_pivoted[i] = if r[[i, i]] != 0.0 {
+            sum / r[[i, i]]
+        } else {
+            0.0
+        };
+    }
+
+    // undo the column pivoting
+    let mut x = Array1::zeros(n);
+    for k in 0..n {
+        x[perm[k]] = x_pivoted[k];
+    }
+
+    (x, r, perm)
+}